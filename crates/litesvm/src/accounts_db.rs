@@ -0,0 +1,146 @@
+use {
+    crate::types::AccountFilter,
+    solana_account::Account,
+    solana_pubkey::Pubkey,
+    std::collections::{HashMap, HashSet},
+};
+
+/// The token account layout bytes that the secondary indexes key off of:
+/// mint at `0..32`, owner at `32..64`. This mirrors the SPL token account
+/// layout without depending on the `spl-token` crate.
+const MINT_RANGE: std::ops::Range<usize> = 0..32;
+const OWNER_RANGE: std::ops::Range<usize> = 32..64;
+
+/// In-memory account store backing a [`crate::LiteSVM`] instance.
+///
+/// Optionally maintains secondary indexes keyed on the token-account owner
+/// and mint fields so that `get_program_accounts_filtered` can look up
+/// candidates directly instead of scanning every account. This is opt-in
+/// (see [`crate::LiteSVM::with_secondary_indexes`]) because maintaining the
+/// indexes costs a little extra work on every account write.
+pub(crate) struct AccountsDb {
+    accounts: HashMap<Pubkey, Account>,
+    secondary_indexes_enabled: bool,
+    owner_index: HashMap<Pubkey, HashSet<Pubkey>>,
+    mint_index: HashMap<Pubkey, HashSet<Pubkey>>,
+}
+
+impl AccountsDb {
+    pub(crate) fn new(secondary_indexes_enabled: bool) -> Self {
+        Self {
+            accounts: HashMap::new(),
+            secondary_indexes_enabled,
+            owner_index: HashMap::new(),
+            mint_index: HashMap::new(),
+        }
+    }
+
+    pub(crate) fn enable_secondary_indexes(&mut self) {
+        self.secondary_indexes_enabled = true;
+    }
+
+    pub(crate) fn get_account(&self, pubkey: &Pubkey) -> Option<Account> {
+        self.accounts.get(pubkey).cloned()
+    }
+
+    pub(crate) fn set_account(&mut self, pubkey: Pubkey, account: Account) {
+        self.unindex(&pubkey);
+        // A zero-lamport account doesn't exist on-chain: Solana's runtime
+        // sweeps these away as soon as a transaction leaves one behind,
+        // independent of (and prior to) any rent-exemption check.
+        if account.lamports == 0 {
+            self.accounts.remove(&pubkey);
+            return;
+        }
+        self.index(&pubkey, &account);
+        self.accounts.insert(pubkey, account);
+    }
+
+    pub(crate) fn get_program_accounts(&self, program: &Pubkey) -> Vec<(Pubkey, Account)> {
+        self.accounts
+            .iter()
+            .filter(|(_, account)| account.owner == *program)
+            .map(|(pubkey, account)| (*pubkey, account.clone()))
+            .collect()
+    }
+
+    pub(crate) fn get_program_accounts_filtered(
+        &self,
+        program: &Pubkey,
+        filters: &[AccountFilter],
+    ) -> Vec<(Pubkey, Account)> {
+        let matches = |account: &Account| {
+            account.owner == *program && filters.iter().all(|filter| filter.matches(account))
+        };
+        if let Some(candidates) = self.indexed_candidates(filters) {
+            return candidates
+                .into_iter()
+                .filter_map(|pubkey| {
+                    self.accounts
+                        .get(&pubkey)
+                        .filter(|account| matches(account))
+                        .map(|account| (pubkey, account.clone()))
+                })
+                .collect();
+        }
+        self.accounts
+            .iter()
+            .filter(|(_, account)| matches(account))
+            .map(|(pubkey, account)| (*pubkey, account.clone()))
+            .collect()
+    }
+
+    /// If the secondary indexes are enabled and one of the filters pins the
+    /// owner or mint field exactly, return the (small) candidate set from
+    /// the index instead of forcing a full scan.
+    fn indexed_candidates(&self, filters: &[AccountFilter]) -> Option<HashSet<Pubkey>> {
+        if !self.secondary_indexes_enabled {
+            return None;
+        }
+        filters.iter().find_map(|filter| {
+            let AccountFilter::Memcmp(memcmp) = filter else {
+                return None;
+            };
+            if memcmp.offset == OWNER_RANGE.start && memcmp.bytes.len() == OWNER_RANGE.len() {
+                let owner = Pubkey::try_from(memcmp.bytes.as_slice()).ok()?;
+                return Some(self.owner_index.get(&owner).cloned().unwrap_or_default());
+            }
+            if memcmp.offset == MINT_RANGE.start && memcmp.bytes.len() == MINT_RANGE.len() {
+                let mint = Pubkey::try_from(memcmp.bytes.as_slice()).ok()?;
+                return Some(self.mint_index.get(&mint).cloned().unwrap_or_default());
+            }
+            None
+        })
+    }
+
+    fn index(&mut self, pubkey: &Pubkey, account: &Account) {
+        if !self.secondary_indexes_enabled || account.data.len() < OWNER_RANGE.end {
+            return;
+        }
+        if let Ok(mint) = Pubkey::try_from(&account.data[MINT_RANGE]) {
+            self.mint_index.entry(mint).or_default().insert(*pubkey);
+        }
+        if let Ok(owner) = Pubkey::try_from(&account.data[OWNER_RANGE]) {
+            self.owner_index.entry(owner).or_default().insert(*pubkey);
+        }
+    }
+
+    fn unindex(&mut self, pubkey: &Pubkey) {
+        let Some(account) = self.accounts.get(pubkey) else {
+            return;
+        };
+        if account.data.len() < OWNER_RANGE.end {
+            return;
+        }
+        if let Ok(mint) = Pubkey::try_from(&account.data[MINT_RANGE]) {
+            if let Some(set) = self.mint_index.get_mut(&mint) {
+                set.remove(pubkey);
+            }
+        }
+        if let Ok(owner) = Pubkey::try_from(&account.data[OWNER_RANGE]) {
+            if let Some(set) = self.owner_index.get_mut(&owner) {
+                set.remove(pubkey);
+            }
+        }
+    }
+}
@@ -0,0 +1,11 @@
+use thiserror::Error;
+
+/// The maximum size of an account's data, mirroring the real runtime's
+/// `MAX_PERMITTED_DATA_LENGTH`.
+pub const MAX_PERMITTED_DATA_LENGTH: usize = 10 * 1024 * 1024;
+
+#[derive(Error, Debug)]
+pub enum LiteSVMError {
+    #[error("account data length {0} exceeds the maximum of {MAX_PERMITTED_DATA_LENGTH}")]
+    AccountDataTooLarge(usize),
+}
@@ -0,0 +1,520 @@
+//! A fast and lightweight Solana VM simulator for testing Solana programs.
+//!
+//! `LiteSVM` only executes System Program instructions today: account
+//! creation/allocation/assignment, lamport transfers, and durable nonce
+//! management. It keeps its own in-memory account store, blockhash queue,
+//! status cache, and rent model rather than embedding a full validator bank.
+
+mod accounts_db;
+pub mod error;
+mod message_processor;
+mod nonce;
+mod rent;
+mod status_cache;
+pub mod types;
+
+use {
+    crate::{
+        accounts_db::AccountsDb,
+        error::LiteSVMError,
+        status_cache::StatusCache,
+        types::{
+            AccountFilter, FailedTransactionMetadata, SimulatedTransactionInfo,
+            TransactionMetadata, TransactionResult,
+        },
+    },
+    solana_account::Account,
+    solana_clock::Clock,
+    solana_epoch_schedule::EpochSchedule,
+    solana_hash::Hash,
+    solana_instruction_error::InstructionError,
+    solana_keypair::Keypair,
+    solana_message::Message,
+    solana_pubkey::Pubkey,
+    solana_rent::Rent,
+    solana_sdk_ids::{native_loader, system_program},
+    solana_signature::Signature,
+    solana_signer::Signer,
+    solana_system_interface::instruction::transfer,
+    solana_transaction::{uses_durable_nonce, Transaction},
+    solana_transaction_error::TransactionError,
+    std::collections::{HashMap, VecDeque},
+};
+
+/// Fee charged per required transaction signature, matching mainnet-beta's
+/// historical default.
+pub(crate) const LAMPORTS_PER_SIGNATURE: u64 = 5000;
+
+/// How many blockhashes (and, by extension, status cache entries) are kept
+/// alive at once, matching the real blockhash queue's window.
+const MAX_BLOCKHASHES: usize = 300;
+
+type TransactionCallback = Box<dyn FnMut(&Transaction, &TransactionResult, &LiteSVM)>;
+type AccountUpdateCallback = Box<dyn FnMut(&Pubkey, &Account, u64)>;
+
+/// An in-memory Solana runtime good enough to exercise System Program
+/// transactions without spinning up a validator.
+pub struct LiteSVM {
+    accounts: AccountsDb,
+    blockhash_queue: VecDeque<Hash>,
+    status_cache: Option<StatusCache>,
+    rent: Rent,
+    epoch_schedule: EpochSchedule,
+    clock: Clock,
+    nonce_counter: u64,
+    faucet: Keypair,
+    transaction_callback: Option<TransactionCallback>,
+    account_update_callback: Option<AccountUpdateCallback>,
+}
+
+impl Default for LiteSVM {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl LiteSVM {
+    pub fn new() -> Self {
+        let mut accounts = AccountsDb::new(false);
+        accounts.set_account(
+            system_program::id(),
+            Account {
+                lamports: 1,
+                data: vec![],
+                owner: native_loader::id(),
+                executable: true,
+                rent_epoch: 0,
+            },
+        );
+        let faucet = Keypair::new();
+        accounts.set_account(
+            faucet.pubkey(),
+            Account {
+                lamports: u64::MAX / 2,
+                data: vec![],
+                owner: system_program::id(),
+                executable: false,
+                rent_epoch: 0,
+            },
+        );
+
+        let mut blockhash_queue = VecDeque::new();
+        blockhash_queue.push_back(Hash::new_unique());
+
+        Self {
+            accounts,
+            blockhash_queue,
+            status_cache: Some(StatusCache::default()),
+            rent: Rent::default(),
+            epoch_schedule: EpochSchedule::default(),
+            clock: Clock::default(),
+            nonce_counter: 0,
+            faucet,
+            transaction_callback: None,
+            account_update_callback: None,
+        }
+    }
+
+    /// Opts into secondary indexes (owner/mint) for
+    /// [`Self::get_program_accounts_filtered`]. Must be called before any
+    /// accounts that should be indexed are created.
+    pub fn with_secondary_indexes(mut self) -> Self {
+        self.accounts.enable_secondary_indexes();
+        self
+    }
+
+    /// Toggles the replay-protection status cache. Disabled, `send_transaction`
+    /// no longer rejects a signature it has already seen.
+    pub fn with_status_cache(mut self, enabled: bool) -> Self {
+        self.status_cache = enabled.then(StatusCache::default);
+        self
+    }
+
+    pub fn with_transaction_callback(
+        mut self,
+        callback: impl FnMut(&Transaction, &TransactionResult, &LiteSVM) + 'static,
+    ) -> Self {
+        self.transaction_callback = Some(Box::new(callback));
+        self
+    }
+
+    pub fn set_transaction_callback(
+        &mut self,
+        callback: impl FnMut(&Transaction, &TransactionResult, &LiteSVM) + 'static,
+    ) {
+        self.transaction_callback = Some(Box::new(callback));
+    }
+
+    pub fn unset_transaction_callback(&mut self) {
+        self.transaction_callback = None;
+    }
+
+    pub fn set_account_update_callback(
+        &mut self,
+        callback: impl FnMut(&Pubkey, &Account, u64) + 'static,
+    ) {
+        self.account_update_callback = Some(Box::new(callback));
+    }
+
+    pub fn unset_account_update_callback(&mut self) {
+        self.account_update_callback = None;
+    }
+
+    /// Reads a sysvar tracked internally by `LiteSVM` (currently just
+    /// [`solana_clock::Clock`]).
+    pub fn get_sysvar<T: Sysvar>(&self) -> T {
+        T::read_from(self)
+    }
+
+    pub fn latest_blockhash(&self) -> Hash {
+        *self.blockhash_queue.back().expect("blockhash queue is never empty")
+    }
+
+    /// Registers a new, unique blockhash and ages the oldest one out of the
+    /// queue once it grows past [`MAX_BLOCKHASHES`]. Also advances the status
+    /// cache by one generation, which drops entries by their own age rather
+    /// than together with the blockhash that leaves the queue.
+    pub fn expire_blockhash(&mut self) {
+        self.blockhash_queue.push_back(Hash::new_unique());
+        while self.blockhash_queue.len() > MAX_BLOCKHASHES {
+            self.blockhash_queue.pop_front();
+        }
+        if let Some(status_cache) = self.status_cache.as_mut() {
+            status_cache.evict_aged_out();
+        }
+    }
+
+    pub fn minimum_balance_for_rent_exemption(&self, data_len: usize) -> u64 {
+        self.rent.minimum_balance(data_len)
+    }
+
+    pub fn get_account(&self, pubkey: &Pubkey) -> Option<Account> {
+        self.accounts.get_account(pubkey)
+    }
+
+    pub fn set_account(&mut self, pubkey: Pubkey, account: Account) -> Result<(), LiteSVMError> {
+        if account.data.len() > error::MAX_PERMITTED_DATA_LENGTH {
+            return Err(LiteSVMError::AccountDataTooLarge(account.data.len()));
+        }
+        self.accounts.set_account(pubkey, account);
+        Ok(())
+    }
+
+    pub fn get_program_accounts(&self, program: &Pubkey) -> Vec<(Pubkey, Account)> {
+        self.accounts.get_program_accounts(program)
+    }
+
+    pub fn get_program_accounts_filtered(
+        &self,
+        program: &Pubkey,
+        filters: &[AccountFilter],
+    ) -> Vec<(Pubkey, Account)> {
+        self.accounts.get_program_accounts_filtered(program, filters)
+    }
+
+    pub fn get_signature_status(&self, signature: &Signature) -> Option<TransactionResult> {
+        self.status_cache
+            .as_ref()
+            .and_then(|cache| cache.get_status(signature))
+    }
+
+    /// Fast-forwards the clock to the given epoch, so that accounts loaded
+    /// as writable afterwards are rent-collected as if that many epochs had
+    /// passed.
+    pub fn warp_to_epoch(&mut self, epoch: u64) {
+        self.clock.epoch = epoch;
+        self.clock.slot = self.epoch_schedule.get_first_slot_in_epoch(epoch);
+    }
+
+    /// Funds `to` with `lamports` via a transfer from an internal,
+    /// effectively-unlimited faucet account, so the recipient's balance
+    /// isn't itself taxed by the transaction fee.
+    pub fn airdrop(&mut self, to: &Pubkey, lamports: u64) -> TransactionResult {
+        let faucet = self.faucet.insecure_clone();
+        let ix = transfer(&faucet.pubkey(), to, lamports);
+        let tx = Transaction::new(
+            &[&faucet],
+            Message::new(&[ix], Some(&faucet.pubkey())),
+            self.latest_blockhash(),
+        );
+        self.send_transaction(tx)
+    }
+
+    pub fn send_transaction(&mut self, tx: Transaction) -> TransactionResult {
+        let result = self.process_transaction(&tx);
+        self.fire_transaction_callback(&tx, &result);
+        result
+    }
+
+    /// Verifies a transaction's signatures, charges its fee and executes its
+    /// instructions like [`Self::send_transaction`], but against a sandboxed
+    /// view of the account store: `overrides` are visible during execution,
+    /// nothing is committed, and the resulting post-execution state of every
+    /// account in the message is returned instead. Unlike
+    /// `send_transaction`, it does not check the recent blockhash, durable
+    /// nonce or status cache, does not collect rent, and fires no callbacks.
+    pub fn simulate_transaction_with_overrides(
+        &self,
+        tx: Transaction,
+        overrides: HashMap<Pubkey, Account>,
+    ) -> Result<SimulatedTransactionInfo, FailedTransactionMetadata> {
+        let message = tx.message().clone();
+        let signature = tx.signatures.first().copied().unwrap_or_default();
+        let fee = message.header.num_required_signatures as u64 * LAMPORTS_PER_SIGNATURE;
+
+        if tx.verify().is_err() {
+            return Err(FailedTransactionMetadata {
+                err: TransactionError::SignatureFailure,
+                meta: TransactionMetadata { signature, fee: 0 },
+            });
+        }
+
+        let mut working_set: HashMap<Pubkey, Account> = message
+            .account_keys
+            .iter()
+            .map(|key| {
+                let account = overrides
+                    .get(key)
+                    .cloned()
+                    .or_else(|| self.accounts.get_account(key))
+                    .unwrap_or_default();
+                (*key, account)
+            })
+            .collect();
+
+        let payer = message.account_keys[0];
+        debit_fee(&mut working_set, payer, fee).map_err(|err| FailedTransactionMetadata {
+            err,
+            meta: TransactionMetadata { signature, fee },
+        })?;
+
+        let mut nonce_counter = self.nonce_counter;
+        for (idx, ix) in message.instructions.iter().enumerate() {
+            message_processor::process_system_instruction(
+                &message,
+                ix,
+                &mut working_set,
+                &self.rent,
+                &self.latest_blockhash(),
+                &mut nonce_counter,
+            )
+            .map_err(|err| FailedTransactionMetadata {
+                err: TransactionError::InstructionError(idx as u8, err),
+                meta: TransactionMetadata { signature, fee },
+            })?;
+        }
+
+        let post_accounts = message
+            .account_keys
+            .iter()
+            .map(|key| (*key, working_set.remove(key).unwrap_or_default()))
+            .collect();
+
+        Ok(SimulatedTransactionInfo {
+            meta: TransactionMetadata { signature, fee },
+            post_accounts,
+        })
+    }
+
+    fn process_transaction(&mut self, tx: &Transaction) -> TransactionResult {
+        let signature = tx.signatures.first().copied().unwrap_or_default();
+        let message = tx.message().clone();
+
+        if tx.verify().is_err() {
+            return Err(FailedTransactionMetadata {
+                err: TransactionError::SignatureFailure,
+                meta: TransactionMetadata { signature, fee: 0 },
+            });
+        }
+
+        let nonce_ix = uses_durable_nonce(tx).cloned();
+
+        if let Some(ix) = &nonce_ix {
+            let nonce_pubkey = message.account_keys[ix.accounts[0] as usize];
+            let nonce_data = self
+                .accounts
+                .get_account(&nonce_pubkey)
+                .as_ref()
+                .and_then(nonce::nonce_versions)
+                .and_then(|versions| {
+                    versions
+                        .verify_recent_blockhash(&message.recent_blockhash)
+                        .cloned()
+                });
+            let Some(nonce_data) = nonce_data else {
+                return Err(FailedTransactionMetadata {
+                    err: TransactionError::BlockhashNotFound,
+                    meta: TransactionMetadata { signature, fee: 0 },
+                });
+            };
+
+            // The nonce authority must have signed, same as
+            // `process_system_instruction`'s `AdvanceNonceAccount` arm checks
+            // at commit time; validating it here too means a mismatched
+            // authority fails before the fee is even debited.
+            let authority_idx = ix.accounts.get(2).copied();
+            let authority_ok = authority_idx
+                .is_some_and(|idx| message.is_signer(idx as usize))
+                && authority_idx
+                    .map(|idx| message.account_keys[idx as usize])
+                    .is_some_and(|authority| authority == nonce_data.authority);
+            if !authority_ok {
+                return Err(FailedTransactionMetadata {
+                    err: TransactionError::InstructionError(
+                        0,
+                        InstructionError::MissingRequiredSignature,
+                    ),
+                    meta: TransactionMetadata { signature, fee: 0 },
+                });
+            }
+        } else if !self.blockhash_queue.contains(&message.recent_blockhash) {
+            return Err(FailedTransactionMetadata {
+                err: TransactionError::BlockhashNotFound,
+                meta: TransactionMetadata { signature, fee: 0 },
+            });
+        }
+
+        if let Some(status_cache) = &self.status_cache {
+            if status_cache.get_status(&signature).is_some() {
+                return Err(FailedTransactionMetadata {
+                    err: TransactionError::AlreadyProcessed,
+                    meta: TransactionMetadata { signature, fee: 0 },
+                });
+            }
+        }
+
+        let fee = message.header.num_required_signatures as u64 * LAMPORTS_PER_SIGNATURE;
+        let payer = message.account_keys[0];
+        let mut payer_account = self.accounts.get_account(&payer).unwrap_or_default();
+        if payer_account.lamports < fee {
+            return Err(FailedTransactionMetadata {
+                err: TransactionError::InsufficientFundsForFee,
+                meta: TransactionMetadata { signature, fee: 0 },
+            });
+        }
+        payer_account.lamports -= fee;
+        rent::collect_rent(&self.rent, &self.epoch_schedule, &mut payer_account, self.clock.epoch);
+        self.commit_account(payer, payer_account);
+
+        // A durable-nonce transaction's nonce always advances, even if the
+        // rest of the transaction later fails.
+        if let Some(ix) = &nonce_ix {
+            let nonce_pubkey = message.account_keys[ix.accounts[0] as usize];
+            let mut working_set = HashMap::new();
+            working_set.insert(nonce_pubkey, self.accounts.get_account(&nonce_pubkey).unwrap_or_default());
+            if let Err(err) = message_processor::process_system_instruction(
+                &message,
+                ix,
+                &mut working_set,
+                &self.rent,
+                &self.latest_blockhash(),
+                &mut self.nonce_counter,
+            ) {
+                let result = Err(FailedTransactionMetadata {
+                    err: TransactionError::InstructionError(0, err),
+                    meta: TransactionMetadata { signature, fee },
+                });
+                self.finish_transaction(&message, &signature, &result);
+                return result;
+            }
+            let mut nonce_account = working_set.remove(&nonce_pubkey).unwrap();
+            rent::collect_rent(&self.rent, &self.epoch_schedule, &mut nonce_account, self.clock.epoch);
+            self.commit_account(nonce_pubkey, nonce_account);
+        }
+
+        let mut working_set: HashMap<Pubkey, Account> = message
+            .account_keys
+            .iter()
+            .map(|key| (*key, self.accounts.get_account(key).unwrap_or_default()))
+            .collect();
+
+        for (idx, ix) in message.instructions.iter().enumerate() {
+            if nonce_ix.is_some() && idx == 0 {
+                continue;
+            }
+            if let Err(err) = message_processor::process_system_instruction(
+                &message,
+                ix,
+                &mut working_set,
+                &self.rent,
+                &self.latest_blockhash(),
+                &mut self.nonce_counter,
+            ) {
+                let result = Err(FailedTransactionMetadata {
+                    err: TransactionError::InstructionError(idx as u8, err),
+                    meta: TransactionMetadata { signature, fee },
+                });
+                self.finish_transaction(&message, &signature, &result);
+                return result;
+            }
+        }
+
+        for (i, key) in message.account_keys.iter().enumerate() {
+            if message.is_maybe_writable_with_reserved_addresses(i, None::<&std::collections::HashSet<Pubkey>>) {
+                if let Some(mut account) = working_set.remove(key) {
+                    rent::collect_rent(&self.rent, &self.epoch_schedule, &mut account, self.clock.epoch);
+                    self.commit_account(*key, account);
+                }
+            }
+        }
+
+        let result = Ok(TransactionMetadata { signature, fee });
+        self.finish_transaction(&message, &signature, &result);
+        result
+    }
+
+    fn finish_transaction(
+        &mut self,
+        message: &Message,
+        signature: &Signature,
+        result: &TransactionResult,
+    ) {
+        if let Some(status_cache) = self.status_cache.as_mut() {
+            status_cache.insert(message.recent_blockhash, *signature, result.clone());
+        }
+    }
+
+    fn commit_account(&mut self, pubkey: Pubkey, account: Account) {
+        self.accounts.set_account(pubkey, account.clone());
+        self.fire_account_update_callback(&pubkey, &account);
+    }
+
+    fn fire_transaction_callback(&mut self, tx: &Transaction, result: &TransactionResult) {
+        if let Some(mut callback) = self.transaction_callback.take() {
+            callback(tx, result, self);
+            self.transaction_callback = Some(callback);
+        }
+    }
+
+    fn fire_account_update_callback(&mut self, pubkey: &Pubkey, account: &Account) {
+        let slot = self.clock.slot;
+        if let Some(mut callback) = self.account_update_callback.take() {
+            callback(pubkey, account, slot);
+            self.account_update_callback = Some(callback);
+        }
+    }
+}
+
+fn debit_fee(
+    working_set: &mut HashMap<Pubkey, Account>,
+    payer: Pubkey,
+    fee: u64,
+) -> Result<(), TransactionError> {
+    let account = working_set.entry(payer).or_default();
+    if account.lamports < fee {
+        return Err(TransactionError::InsufficientFundsForFee);
+    }
+    account.lamports -= fee;
+    Ok(())
+}
+
+/// A sysvar `LiteSVM` can hand back via [`LiteSVM::get_sysvar`].
+pub trait Sysvar: Clone {
+    fn read_from(svm: &LiteSVM) -> Self;
+}
+
+impl Sysvar for Clock {
+    fn read_from(svm: &LiteSVM) -> Self {
+        svm.clock.clone()
+    }
+}
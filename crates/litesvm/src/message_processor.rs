@@ -0,0 +1,196 @@
+use {
+    crate::nonce,
+    solana_account::Account,
+    solana_hash::Hash,
+    solana_instruction_error::InstructionError,
+    solana_message::{compiled_instruction::CompiledInstruction, legacy::Message},
+    solana_nonce::state::State as NonceState,
+    solana_pubkey::Pubkey,
+    solana_rent::Rent,
+    solana_sdk_ids::system_program,
+    solana_system_interface::instruction::SystemInstruction,
+    std::collections::HashMap,
+};
+
+/// Executes a single System Program instruction against the working set of
+/// accounts loaded for a transaction, mutating it in place.
+///
+/// `nonce_counter` is bumped on every instruction that assigns a fresh
+/// durable-nonce value (see [`crate::nonce::fresh_durable_nonce`]).
+pub(crate) fn process_system_instruction(
+    message: &Message,
+    ix: &CompiledInstruction,
+    accounts: &mut HashMap<Pubkey, Account>,
+    rent: &Rent,
+    current_blockhash: &Hash,
+    nonce_counter: &mut u64,
+) -> Result<(), InstructionError> {
+    if message.account_keys[ix.program_id_index as usize] != system_program::id() {
+        return Err(InstructionError::IncorrectProgramId);
+    }
+
+    let key = |slot: usize| -> Result<Pubkey, InstructionError> {
+        let index = *ix.accounts.get(slot).ok_or(InstructionError::MissingAccount)?;
+        Ok(message.account_keys[index as usize])
+    };
+    let is_signer = |slot: usize| -> bool {
+        ix.accounts
+            .get(slot)
+            .is_some_and(|&index| message.is_signer(index as usize))
+    };
+
+    let instruction: SystemInstruction =
+        bincode::deserialize(&ix.data).map_err(|_| InstructionError::InvalidInstructionData)?;
+
+    match instruction {
+        SystemInstruction::CreateAccount {
+            lamports,
+            space,
+            owner,
+        } => {
+            let (from, to) = (key(0)?, key(1)?);
+            if !is_signer(0) || !is_signer(1) {
+                return Err(InstructionError::MissingRequiredSignature);
+            }
+            if accounts.get(&to).is_some_and(|a| a.lamports > 0) {
+                return Err(InstructionError::AccountAlreadyInitialized);
+            }
+            debit(accounts, from, lamports)?;
+            accounts.insert(
+                to,
+                Account {
+                    lamports,
+                    data: vec![0; space as usize],
+                    owner,
+                    executable: false,
+                    rent_epoch: 0,
+                },
+            );
+        }
+        SystemInstruction::Assign { owner } => {
+            let address = key(0)?;
+            if !is_signer(0) {
+                return Err(InstructionError::MissingRequiredSignature);
+            }
+            load(accounts, address).owner = owner;
+        }
+        SystemInstruction::Transfer { lamports } => {
+            let (from, to) = (key(0)?, key(1)?);
+            if !is_signer(0) {
+                return Err(InstructionError::MissingRequiredSignature);
+            }
+            if !load(accounts, from).data.is_empty() {
+                return Err(InstructionError::InvalidAccountData);
+            }
+            debit(accounts, from, lamports)?;
+            load(accounts, to).lamports += lamports;
+        }
+        SystemInstruction::Allocate { space } => {
+            let address = key(0)?;
+            if !is_signer(0) {
+                return Err(InstructionError::MissingRequiredSignature);
+            }
+            let account = load(accounts, address);
+            if account.owner != system_program::id() {
+                return Err(InstructionError::IncorrectProgramId);
+            }
+            if !account.data.is_empty() {
+                return Err(InstructionError::AccountAlreadyInitialized);
+            }
+            account.data = vec![0; space as usize];
+        }
+        SystemInstruction::InitializeNonceAccount(authority) => {
+            let nonce_pubkey = key(0)?;
+            if nonce::nonce_versions(load(accounts, nonce_pubkey))
+                .is_some_and(|v| *v.state() != NonceState::Uninitialized)
+            {
+                return Err(InstructionError::AccountAlreadyInitialized);
+            }
+            *nonce_counter += 1;
+            let durable_nonce =
+                nonce::fresh_durable_nonce(current_blockhash, &nonce_pubkey, *nonce_counter);
+            let lamports_per_signature = crate::LAMPORTS_PER_SIGNATURE;
+            let account = load(accounts, nonce_pubkey);
+            account.owner = system_program::id();
+            account.data =
+                nonce::serialize_nonce_state(authority, durable_nonce, lamports_per_signature);
+        }
+        SystemInstruction::AdvanceNonceAccount => {
+            let nonce_pubkey = key(0)?;
+            let authority = key(2)?;
+            if !is_signer(2) {
+                return Err(InstructionError::MissingRequiredSignature);
+            }
+            let data = accounts
+                .get(&nonce_pubkey)
+                .and_then(nonce::initialized_nonce_data)
+                .ok_or(InstructionError::InvalidAccountData)?;
+            if data.authority != authority {
+                return Err(InstructionError::MissingRequiredSignature);
+            }
+            *nonce_counter += 1;
+            let durable_nonce =
+                nonce::fresh_durable_nonce(current_blockhash, &nonce_pubkey, *nonce_counter);
+            let lamports_per_signature = crate::LAMPORTS_PER_SIGNATURE;
+            load(accounts, nonce_pubkey).data =
+                nonce::serialize_nonce_state(data.authority, durable_nonce, lamports_per_signature);
+        }
+        SystemInstruction::WithdrawNonceAccount(lamports) => {
+            let nonce_pubkey = key(0)?;
+            let to = key(1)?;
+            let authority = key(4)?;
+            if !is_signer(4) {
+                return Err(InstructionError::MissingRequiredSignature);
+            }
+            let nonce_account = accounts
+                .get(&nonce_pubkey)
+                .ok_or(InstructionError::MissingAccount)?;
+            match nonce::initialized_nonce_data(nonce_account) {
+                Some(data) if data.authority == authority => {
+                    let remaining = nonce_account
+                        .lamports
+                        .checked_sub(lamports)
+                        .ok_or(InstructionError::InsufficientFunds)?;
+                    if remaining != 0 && !rent.is_exempt(remaining, nonce_account.data.len()) {
+                        return Err(InstructionError::InsufficientFunds);
+                    }
+                }
+                Some(_) => return Err(InstructionError::MissingRequiredSignature),
+                // An uninitialized nonce account can be withdrawn from freely,
+                // as long as the account itself signed.
+                None if is_signer(0) => {}
+                None => return Err(InstructionError::MissingRequiredSignature),
+            }
+            debit(accounts, nonce_pubkey, lamports)?;
+            load(accounts, to).lamports += lamports;
+        }
+        _ => return Err(InstructionError::InvalidInstructionData),
+    }
+    Ok(())
+}
+
+fn load(accounts: &mut HashMap<Pubkey, Account>, pubkey: Pubkey) -> &mut Account {
+    accounts.entry(pubkey).or_insert_with(|| Account {
+        lamports: 0,
+        data: vec![],
+        owner: system_program::id(),
+        executable: false,
+        rent_epoch: 0,
+    })
+}
+
+fn debit(
+    accounts: &mut HashMap<Pubkey, Account>,
+    pubkey: Pubkey,
+    lamports: u64,
+) -> Result<(), InstructionError> {
+    let account = load(accounts, pubkey);
+    if account.owner != system_program::id() {
+        return Err(InstructionError::IncorrectProgramId);
+    }
+    account.lamports = account
+        .lamports
+        .checked_sub(lamports)
+        .ok_or(InstructionError::InsufficientFunds)?;
+    Ok(())
+}
@@ -0,0 +1,63 @@
+use {
+    solana_account::Account,
+    solana_hash::Hash,
+    solana_nonce::{
+        state::{Data, DurableNonce, State},
+        versions::Versions,
+    },
+    solana_pubkey::Pubkey,
+    solana_sdk_ids::system_program,
+    solana_sha256_hasher::hashv,
+};
+
+/// On-chain size of a nonce account, matching `solana_nonce::state::State::size()`.
+pub(crate) fn nonce_account_size() -> usize {
+    State::size()
+}
+
+/// Parses a nonce account's data, returning `None` if the account isn't
+/// system-owned, isn't sized like a nonce account, or fails to deserialize.
+pub(crate) fn nonce_versions(account: &Account) -> Option<Versions> {
+    if account.owner != system_program::id() || account.data.len() != nonce_account_size() {
+        return None;
+    }
+    bincode::deserialize(&account.data).ok()
+}
+
+/// Returns the `Data` of an *initialized* nonce account, if any.
+pub(crate) fn initialized_nonce_data(account: &Account) -> Option<Data> {
+    match nonce_versions(account)?.state() {
+        State::Initialized(data) => Some(data.clone()),
+        State::Uninitialized => None,
+    }
+}
+
+pub(crate) fn serialize_nonce_state(
+    authority: Pubkey,
+    durable_nonce: DurableNonce,
+    lamports_per_signature: u64,
+) -> Vec<u8> {
+    let data = Data::new(authority, durable_nonce, lamports_per_signature);
+    bincode::serialize(&Versions::new(State::Initialized(data))).expect("nonce state serializes")
+}
+
+/// Derives the value a nonce account is (re-)initialized or advanced to.
+///
+/// LiteSVM's blockhash queue only moves when a caller explicitly expires it
+/// (see `LiteSVM::expire_blockhash`), so the current blockhash alone isn't
+/// enough to guarantee a nonce account gets a fresh value on every advance.
+/// Mixing in the nonce account's own address and a counter that's bumped on
+/// every initialize/advance keeps each value unique, the same way a real
+/// validator's ever-advancing blockhash would.
+pub(crate) fn fresh_durable_nonce(
+    current_blockhash: &Hash,
+    nonce_pubkey: &Pubkey,
+    counter: u64,
+) -> DurableNonce {
+    let seed = hashv(&[
+        current_blockhash.as_ref(),
+        nonce_pubkey.as_ref(),
+        &counter.to_le_bytes(),
+    ]);
+    DurableNonce::from_blockhash(&seed)
+}
@@ -0,0 +1,53 @@
+use {
+    solana_account::Account,
+    solana_clock::{DEFAULT_MS_PER_SLOT, SECONDS_PER_DAY},
+    solana_epoch_schedule::EpochSchedule,
+    solana_rent::{Rent, ACCOUNT_STORAGE_OVERHEAD},
+};
+
+/// Seconds in a (Julian) year. Divided by the default slot time it gives
+/// roughly how many slots the network produces in a year, used to turn the
+/// rent-exempt deposit (a lifetime balance) into a per-epoch accrual rate.
+/// Matches the constant the runtime historically used to convert
+/// `years_elapsed` into slots for rent collection.
+const SECONDS_PER_YEAR: u64 = (365.25 * SECONDS_PER_DAY as f64) as u64;
+
+/// Epochs per year for a given schedule, i.e. how many non-exempt epochs it
+/// takes for an account to owe its entire rent-exempt deposit.
+fn epochs_per_year(epoch_schedule: &EpochSchedule) -> u64 {
+    let slots_per_year = SECONDS_PER_YEAR * 1000 / DEFAULT_MS_PER_SLOT;
+    (slots_per_year / epoch_schedule.slots_per_epoch).max(1)
+}
+
+/// Collects rent on a single writable account for the epochs that have
+/// elapsed since it was last touched.
+///
+/// Executable accounts and accounts already at or above the rent-exempt minimum
+/// are left untouched (their `rent_epoch` is simply bumped so they don't
+/// accrue a debt while exempt). A non-exempt account is charged a fraction
+/// of its rent-exempt deposit per elapsed epoch — enough epochs elapsing
+/// charges up to the full deposit, never more; it never goes negative, so
+/// an account that can't keep up with rent is drained to zero lamports,
+/// which causes it to be purged by [`crate::accounts_db::AccountsDb`] like
+/// any other zero-lamport account.
+pub(crate) fn collect_rent(
+    rent: &Rent,
+    epoch_schedule: &EpochSchedule,
+    account: &mut Account,
+    current_epoch: u64,
+) {
+    if account.executable || rent.is_exempt(account.lamports, account.data.len()) {
+        account.rent_epoch = current_epoch;
+        return;
+    }
+    let epochs_elapsed = current_epoch.saturating_sub(account.rent_epoch);
+    if epochs_elapsed == 0 {
+        return;
+    }
+    let exemption_deposit =
+        (account.data.len() as u64 + ACCOUNT_STORAGE_OVERHEAD) * rent.lamports_per_byte;
+    let due_per_epoch = exemption_deposit / epochs_per_year(epoch_schedule);
+    let due = due_per_epoch.saturating_mul(epochs_elapsed);
+    account.lamports = account.lamports.saturating_sub(due);
+    account.rent_epoch = current_epoch;
+}
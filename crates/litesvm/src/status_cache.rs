@@ -0,0 +1,62 @@
+use {
+    crate::types::TransactionResult,
+    solana_hash::Hash,
+    solana_signature::Signature,
+    std::collections::{HashMap, VecDeque},
+};
+
+/// How many `expire_blockhash` generations a cache entry survives before
+/// aging out, matching the real runtime's `MAX_CACHE_ENTRIES`. Tracked
+/// per-entry rather than by checking blockhash-queue membership, since a
+/// durable-nonce transaction's key is the nonce's stored value and is never
+/// itself pushed onto the blockhash queue.
+const MAX_CACHE_ENTRIES: u64 = 300;
+
+/// Tracks every signature processed against a given blockhash (or, for a
+/// durable-nonce transaction, the nonce value it advanced) so that replays
+/// of the same signature can be rejected with `AlreadyProcessed` while that
+/// key is still within the cache's window. Entries age out together.
+#[derive(Default)]
+pub(crate) struct StatusCache {
+    // Front = oldest entry, back = newest.
+    blockhashes: VecDeque<(Hash, u64)>,
+    by_blockhash: HashMap<Hash, HashMap<Signature, TransactionResult>>,
+    generation: u64,
+}
+
+impl StatusCache {
+    pub(crate) fn get_status(&self, signature: &Signature) -> Option<TransactionResult> {
+        self.by_blockhash
+            .values()
+            .find_map(|sigs| sigs.get(signature).cloned())
+    }
+
+    pub(crate) fn insert(
+        &mut self,
+        blockhash: Hash,
+        signature: Signature,
+        result: TransactionResult,
+    ) {
+        if !self.blockhashes.iter().any(|(hash, _)| *hash == blockhash) {
+            self.blockhashes.push_back((blockhash, self.generation));
+        }
+        self.by_blockhash
+            .entry(blockhash)
+            .or_default()
+            .insert(signature, result);
+    }
+
+    /// Advances the cache's notion of time by one `expire_blockhash` call
+    /// and drops any entry that has aged past [`MAX_CACHE_ENTRIES`]
+    /// generations.
+    pub(crate) fn evict_aged_out(&mut self) {
+        self.generation += 1;
+        while let Some((_, inserted_at)) = self.blockhashes.front() {
+            if self.generation - inserted_at <= MAX_CACHE_ENTRIES {
+                break;
+            }
+            let (oldest, _) = self.blockhashes.pop_front().expect("checked above");
+            self.by_blockhash.remove(&oldest);
+        }
+    }
+}
@@ -0,0 +1,63 @@
+use {
+    solana_account::Account, solana_pubkey::Pubkey, solana_signature::Signature,
+    solana_transaction_error::TransactionError,
+};
+
+/// Metadata about a transaction that was successfully committed.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct TransactionMetadata {
+    pub signature: Signature,
+    pub fee: u64,
+}
+
+/// A transaction that failed during processing, together with whatever
+/// metadata could still be recovered (e.g. the fee charged to the payer).
+#[derive(Debug, Clone, PartialEq)]
+pub struct FailedTransactionMetadata {
+    pub err: TransactionError,
+    pub meta: TransactionMetadata,
+}
+
+pub type TransactionResult = std::result::Result<TransactionMetadata, FailedTransactionMetadata>;
+
+/// The result of [`crate::LiteSVM::simulate_transaction_with_overrides`]: the
+/// usual transaction metadata, plus the post-execution state of every
+/// account the transaction touched. None of this is committed to the
+/// LiteSVM state.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SimulatedTransactionInfo {
+    pub meta: TransactionMetadata,
+    pub post_accounts: Vec<(Pubkey, Account)>,
+}
+
+/// A filter passed to [`crate::LiteSVM::get_program_accounts_filtered`],
+/// mirroring the RPC `getProgramAccounts` filters.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AccountFilter {
+    /// Keep accounts whose data is exactly this many bytes long.
+    DataSize(usize),
+    /// Keep accounts whose data matches at a given offset.
+    Memcmp(MemcmpFilter),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MemcmpFilter {
+    pub offset: usize,
+    pub bytes: Vec<u8>,
+}
+
+impl AccountFilter {
+    pub(crate) fn matches(&self, account: &Account) -> bool {
+        match self {
+            AccountFilter::DataSize(size) => account.data.len() == *size,
+            AccountFilter::Memcmp(filter) => filter.matches(&account.data),
+        }
+    }
+}
+
+impl MemcmpFilter {
+    pub(crate) fn matches(&self, data: &[u8]) -> bool {
+        let end = self.offset.saturating_add(self.bytes.len());
+        data.get(self.offset..end) == Some(self.bytes.as_slice())
+    }
+}
@@ -6,9 +6,12 @@ use {
     solana_signer::Signer,
     solana_system_interface::instruction::transfer,
     solana_transaction::Transaction,
-    std::sync::{
-        atomic::{AtomicBool, AtomicU64, Ordering},
-        Arc, Mutex,
+    std::{
+        collections::HashSet,
+        sync::{
+            atomic::{AtomicBool, AtomicU64, Ordering},
+            Arc, Mutex,
+        },
     },
 };
 
@@ -151,3 +154,82 @@ fn with_transaction_callback_builder() {
 
     assert!(call_count.load(Ordering::Relaxed) > 0);
 }
+
+#[test]
+fn account_update_callback_fires_for_modified_accounts() {
+    let updated_keys = Arc::new(Mutex::new(HashSet::new()));
+    let keys_clone = updated_keys.clone();
+
+    let mut svm = LiteSVM::new();
+    svm.set_account_update_callback(move |pubkey, _account, _slot| {
+        keys_clone.lock().unwrap().insert(*pubkey);
+    });
+
+    let from_keypair = Keypair::new();
+    let from = from_keypair.pubkey();
+    let to = Address::new_unique();
+    svm.airdrop(&from, 1_000_000).unwrap();
+
+    let ix = transfer(&from, &to, 100);
+    let tx = Transaction::new(
+        &[&from_keypair],
+        Message::new(&[ix], Some(&from)),
+        svm.latest_blockhash(),
+    );
+    svm.send_transaction(tx).unwrap();
+
+    let keys = updated_keys.lock().unwrap();
+    assert!(keys.contains(&from));
+    assert!(keys.contains(&to));
+}
+
+#[test]
+fn account_update_callback_receives_post_state_and_slot() {
+    let last_seen = Arc::new(Mutex::new(None));
+    let last_seen_clone = last_seen.clone();
+
+    let mut svm = LiteSVM::new();
+    svm.set_account_update_callback(move |pubkey, account, slot| {
+        *last_seen_clone.lock().unwrap() = Some((*pubkey, account.lamports, slot));
+    });
+
+    let from_keypair = Keypair::new();
+    let from = from_keypair.pubkey();
+    svm.airdrop(&from, 1_000_000).unwrap();
+
+    let (pubkey, lamports, slot) = last_seen.lock().unwrap().unwrap();
+    assert_eq!(pubkey, from);
+    assert_eq!(lamports, 1_000_000);
+    assert_eq!(slot, svm.get_sysvar::<solana_clock::Clock>().slot);
+}
+
+#[test]
+fn unset_account_update_callback_stops_invocations() {
+    let call_count = Arc::new(AtomicU64::new(0));
+    let count_clone = call_count.clone();
+
+    let mut svm = LiteSVM::new();
+    svm.set_account_update_callback(move |_pubkey, _account, _slot| {
+        count_clone.fetch_add(1, Ordering::Relaxed);
+    });
+
+    let from_keypair = Keypair::new();
+    let from = from_keypair.pubkey();
+    svm.airdrop(&from, 1_000_000).unwrap();
+
+    let count_after_airdrop = call_count.load(Ordering::Relaxed);
+    assert!(count_after_airdrop > 0);
+
+    svm.unset_account_update_callback();
+
+    let to = Address::new_unique();
+    let ix = transfer(&from, &to, 100);
+    let tx = Transaction::new(
+        &[&from_keypair],
+        Message::new(&[ix], Some(&from)),
+        svm.latest_blockhash(),
+    );
+    svm.send_transaction(tx).unwrap();
+
+    assert_eq!(call_count.load(Ordering::Relaxed), count_after_airdrop);
+}
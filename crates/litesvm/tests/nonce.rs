@@ -0,0 +1,204 @@
+use {
+    litesvm::LiteSVM,
+    solana_hash::Hash,
+    solana_keypair::Keypair,
+    solana_message::Message,
+    solana_native_token::LAMPORTS_PER_SOL,
+    solana_nonce::{
+        state::State as NonceState,
+        versions::Versions as NonceVersions,
+    },
+    solana_signer::Signer,
+    solana_system_interface::instruction::{
+        advance_nonce_account, create_nonce_account, transfer, withdraw_nonce_account,
+    },
+    solana_transaction::Transaction,
+};
+
+fn nonce_blockhash(svm: &LiteSVM, nonce_pubkey: &solana_pubkey::Pubkey) -> Hash {
+    let account = svm.get_account(nonce_pubkey).unwrap();
+    match bincode::deserialize::<NonceVersions>(&account.data).unwrap().state() {
+        NonceState::Initialized(data) => data.blockhash(),
+        NonceState::Uninitialized => panic!("nonce account not initialized"),
+    }
+}
+
+#[test_log::test]
+fn create_and_advance_nonce_account() {
+    let mut svm = LiteSVM::new();
+    let payer_keypair = Keypair::new();
+    let payer = payer_keypair.pubkey();
+    let nonce_keypair = Keypair::new();
+    let nonce_pubkey = nonce_keypair.pubkey();
+    svm.airdrop(&payer, 10 * LAMPORTS_PER_SOL).unwrap();
+
+    let rent = svm.minimum_balance_for_rent_exemption(NonceState::size());
+    let ixs = create_nonce_account(&payer, &nonce_pubkey, &payer, rent);
+    let tx = Transaction::new(
+        &[&payer_keypair, &nonce_keypair],
+        Message::new(&ixs, Some(&payer)),
+        svm.latest_blockhash(),
+    );
+    svm.send_transaction(tx).unwrap();
+
+    let stored_blockhash = nonce_blockhash(&svm, &nonce_pubkey);
+
+    let ix = advance_nonce_account(&nonce_pubkey, &payer);
+    let tx = Transaction::new(
+        &[&payer_keypair],
+        Message::new(&[ix], Some(&payer)),
+        stored_blockhash,
+    );
+    svm.send_transaction(tx).unwrap();
+
+    let advanced_blockhash = nonce_blockhash(&svm, &nonce_pubkey);
+    assert_ne!(stored_blockhash, advanced_blockhash);
+}
+
+#[test_log::test]
+fn durable_transaction_survives_blockhash_queue_eviction() {
+    let mut svm = LiteSVM::new();
+    let payer_keypair = Keypair::new();
+    let payer = payer_keypair.pubkey();
+    let nonce_keypair = Keypair::new();
+    let nonce_pubkey = nonce_keypair.pubkey();
+    let to = solana_pubkey::Pubkey::new_unique();
+    svm.airdrop(&payer, 10 * LAMPORTS_PER_SOL).unwrap();
+
+    let rent = svm.minimum_balance_for_rent_exemption(NonceState::size());
+    let ixs = create_nonce_account(&payer, &nonce_pubkey, &payer, rent);
+    let tx = Transaction::new(
+        &[&payer_keypair, &nonce_keypair],
+        Message::new(&ixs, Some(&payer)),
+        svm.latest_blockhash(),
+    );
+    svm.send_transaction(tx).unwrap();
+
+    let durable_blockhash = nonce_blockhash(&svm, &nonce_pubkey);
+
+    // Age out the current blockhash queue so an ordinary transaction using
+    // `durable_blockhash` would now be rejected, while the nonce-backed one
+    // still succeeds because it doesn't consult the queue.
+    for _ in 0..400 {
+        svm.expire_blockhash();
+    }
+
+    let advance_ix = advance_nonce_account(&nonce_pubkey, &payer);
+    let transfer_ix = transfer(&payer, &to, 1_000_000);
+    let tx = Transaction::new(
+        &[&payer_keypair],
+        Message::new(&[advance_ix, transfer_ix], Some(&payer)),
+        durable_blockhash,
+    );
+    let result = svm.send_transaction(tx);
+    assert!(result.is_ok());
+    assert_eq!(svm.get_account(&to).unwrap().lamports, 1_000_000);
+}
+
+#[test_log::test]
+fn nonce_advances_even_on_transaction_failure() {
+    let mut svm = LiteSVM::new();
+    let payer_keypair = Keypair::new();
+    let payer = payer_keypair.pubkey();
+    let nonce_keypair = Keypair::new();
+    let nonce_pubkey = nonce_keypair.pubkey();
+    let to = solana_pubkey::Pubkey::new_unique();
+    svm.airdrop(&payer, 10 * LAMPORTS_PER_SOL).unwrap();
+
+    let rent = svm.minimum_balance_for_rent_exemption(NonceState::size());
+    let ixs = create_nonce_account(&payer, &nonce_pubkey, &payer, rent);
+    let tx = Transaction::new(
+        &[&payer_keypair, &nonce_keypair],
+        Message::new(&ixs, Some(&payer)),
+        svm.latest_blockhash(),
+    );
+    svm.send_transaction(tx).unwrap();
+
+    let durable_blockhash = nonce_blockhash(&svm, &nonce_pubkey);
+    let payer_balance_before = svm.get_account(&payer).unwrap().lamports;
+
+    let advance_ix = advance_nonce_account(&nonce_pubkey, &payer);
+    // Way more than the payer holds, so the inner transfer fails.
+    let transfer_ix = transfer(&payer, &to, 1_000 * LAMPORTS_PER_SOL);
+    let tx = Transaction::new(
+        &[&payer_keypair],
+        Message::new(&[advance_ix, transfer_ix], Some(&payer)),
+        durable_blockhash,
+    );
+    let result = svm.send_transaction(tx);
+    assert!(result.is_err());
+
+    // The nonce should still have advanced, and only the fee should have
+    // been debited from the payer.
+    let advanced_blockhash = nonce_blockhash(&svm, &nonce_pubkey);
+    assert_ne!(durable_blockhash, advanced_blockhash);
+    assert_eq!(
+        svm.get_account(&payer).unwrap().lamports,
+        payer_balance_before - 5000
+    );
+}
+
+#[test_log::test]
+fn advance_nonce_account_rejects_mismatched_authority() {
+    let mut svm = LiteSVM::new();
+    let payer_keypair = Keypair::new();
+    let payer = payer_keypair.pubkey();
+    let nonce_keypair = Keypair::new();
+    let nonce_pubkey = nonce_keypair.pubkey();
+    let wrong_authority_keypair = Keypair::new();
+    let wrong_authority = wrong_authority_keypair.pubkey();
+    svm.airdrop(&payer, 10 * LAMPORTS_PER_SOL).unwrap();
+    svm.airdrop(&wrong_authority, 10 * LAMPORTS_PER_SOL).unwrap();
+
+    let rent = svm.minimum_balance_for_rent_exemption(NonceState::size());
+    let ixs = create_nonce_account(&payer, &nonce_pubkey, &payer, rent);
+    let tx = Transaction::new(
+        &[&payer_keypair, &nonce_keypair],
+        Message::new(&ixs, Some(&payer)),
+        svm.latest_blockhash(),
+    );
+    svm.send_transaction(tx).unwrap();
+
+    let durable_blockhash = nonce_blockhash(&svm, &nonce_pubkey);
+
+    // `wrong_authority` validly signs the transaction but isn't the nonce
+    // account's configured authority (that's `payer`), so the advance must
+    // be rejected with a transaction error instead of panicking.
+    let advance_ix = advance_nonce_account(&nonce_pubkey, &wrong_authority);
+    let tx = Transaction::new(
+        &[&wrong_authority_keypair],
+        Message::new(&[advance_ix], Some(&wrong_authority)),
+        durable_blockhash,
+    );
+    let result = svm.send_transaction(tx);
+    assert!(result.is_err());
+}
+
+#[test_log::test]
+fn withdraw_nonce_account_returns_rent() {
+    let mut svm = LiteSVM::new();
+    let payer_keypair = Keypair::new();
+    let payer = payer_keypair.pubkey();
+    let nonce_keypair = Keypair::new();
+    let nonce_pubkey = nonce_keypair.pubkey();
+    svm.airdrop(&payer, 10 * LAMPORTS_PER_SOL).unwrap();
+
+    let rent = svm.minimum_balance_for_rent_exemption(NonceState::size());
+    let ixs = create_nonce_account(&payer, &nonce_pubkey, &payer, rent);
+    let tx = Transaction::new(
+        &[&payer_keypair, &nonce_keypair],
+        Message::new(&ixs, Some(&payer)),
+        svm.latest_blockhash(),
+    );
+    svm.send_transaction(tx).unwrap();
+
+    let ix = withdraw_nonce_account(&nonce_pubkey, &payer, &payer, rent);
+    let tx = Transaction::new(
+        &[&payer_keypair],
+        Message::new(&[ix], Some(&payer)),
+        svm.latest_blockhash(),
+    );
+    svm.send_transaction(tx).unwrap();
+
+    assert!(svm.get_account(&nonce_pubkey).is_none());
+}
@@ -0,0 +1,149 @@
+use {
+    litesvm::{
+        types::{AccountFilter, MemcmpFilter},
+        LiteSVM,
+    },
+    solana_keypair::Keypair,
+    solana_message::Message,
+    solana_native_token::LAMPORTS_PER_SOL,
+    solana_pubkey::Pubkey,
+    solana_signer::Signer,
+    solana_system_interface::instruction::create_account,
+    solana_transaction::Transaction,
+};
+
+fn create_owned_account(
+    svm: &mut LiteSVM,
+    payer_keypair: &Keypair,
+    owner: &Pubkey,
+    space: usize,
+) -> Pubkey {
+    let payer = payer_keypair.pubkey();
+    let account_kp = Keypair::new();
+    let rent = svm.minimum_balance_for_rent_exemption(space);
+
+    let tx = Transaction::new(
+        &[payer_keypair, &account_kp],
+        Message::new(
+            &[create_account(
+                &payer,
+                &account_kp.pubkey(),
+                rent,
+                space as u64,
+                owner,
+            )],
+            Some(&payer),
+        ),
+        svm.latest_blockhash(),
+    );
+    svm.send_transaction(tx).unwrap();
+    account_kp.pubkey()
+}
+
+#[test_log::test]
+fn filters_by_data_size() {
+    let mut svm = LiteSVM::new();
+    let payer_keypair = Keypair::new();
+    let payer = payer_keypair.pubkey();
+    svm.airdrop(&payer, 10 * LAMPORTS_PER_SOL).unwrap();
+
+    let program = Pubkey::new_unique();
+    let small = create_owned_account(&mut svm, &payer_keypair, &program, 10);
+    let _large = create_owned_account(&mut svm, &payer_keypair, &program, 20);
+
+    let accounts = svm.get_program_accounts_filtered(&program, &[AccountFilter::DataSize(10)]);
+
+    assert_eq!(accounts.len(), 1);
+    assert_eq!(accounts[0].0, small);
+}
+
+#[test_log::test]
+fn filters_by_memcmp() {
+    let mut svm = LiteSVM::new();
+    let payer_keypair = Keypair::new();
+    let payer = payer_keypair.pubkey();
+    svm.airdrop(&payer, 10 * LAMPORTS_PER_SOL).unwrap();
+
+    let program = Pubkey::new_unique();
+    let account = create_owned_account(&mut svm, &payer_keypair, &program, 64);
+
+    // The account's data starts out zeroed, so a memcmp against zero bytes at
+    // any in-range offset should match.
+    let accounts = svm.get_program_accounts_filtered(
+        &program,
+        &[AccountFilter::Memcmp(MemcmpFilter {
+            offset: 32,
+            bytes: vec![0; 32],
+        })],
+    );
+
+    assert_eq!(accounts.len(), 1);
+    assert_eq!(accounts[0].0, account);
+
+    // A non-matching pattern should exclude the account.
+    let no_match = svm.get_program_accounts_filtered(
+        &program,
+        &[AccountFilter::Memcmp(MemcmpFilter {
+            offset: 32,
+            bytes: vec![1; 32],
+        })],
+    );
+    assert!(no_match.is_empty());
+}
+
+#[test_log::test]
+fn memcmp_out_of_range_rejects_account() {
+    let mut svm = LiteSVM::new();
+    let payer_keypair = Keypair::new();
+    let payer = payer_keypair.pubkey();
+    svm.airdrop(&payer, 10 * LAMPORTS_PER_SOL).unwrap();
+
+    let program = Pubkey::new_unique();
+    let _account = create_owned_account(&mut svm, &payer_keypair, &program, 16);
+
+    let accounts = svm.get_program_accounts_filtered(
+        &program,
+        &[AccountFilter::Memcmp(MemcmpFilter {
+            offset: 8,
+            bytes: vec![0; 16],
+        })],
+    );
+
+    assert!(accounts.is_empty());
+}
+
+#[test_log::test]
+fn secondary_index_finds_token_accounts_by_owner() {
+    let mut svm = LiteSVM::new().with_secondary_indexes();
+    let payer_keypair = Keypair::new();
+    let payer = payer_keypair.pubkey();
+    svm.airdrop(&payer, 10 * LAMPORTS_PER_SOL).unwrap();
+
+    let token_program = Pubkey::new_unique();
+    let mint = Pubkey::new_unique();
+    let owner = Pubkey::new_unique();
+
+    let token_account = create_owned_account(&mut svm, &payer_keypair, &token_program, 72);
+    let mut data = vec![0u8; 72];
+    data[0..32].copy_from_slice(&mint.to_bytes());
+    data[32..64].copy_from_slice(&owner.to_bytes());
+    svm.set_account(
+        token_account,
+        solana_account::Account {
+            data,
+            ..svm.get_account(&token_account).unwrap()
+        },
+    )
+    .unwrap();
+
+    let accounts = svm.get_program_accounts_filtered(
+        &token_program,
+        &[AccountFilter::Memcmp(MemcmpFilter {
+            offset: 32,
+            bytes: owner.to_bytes().to_vec(),
+        })],
+    );
+
+    assert_eq!(accounts.len(), 1);
+    assert_eq!(accounts[0].0, token_account);
+}
@@ -0,0 +1,206 @@
+use {
+    litesvm::LiteSVM,
+    solana_account::Account,
+    solana_keypair::Keypair,
+    solana_message::Message,
+    solana_native_token::LAMPORTS_PER_SOL,
+    solana_signer::Signer,
+    solana_system_interface::instruction::{allocate, assign, create_account, transfer},
+    solana_transaction::Transaction,
+};
+
+#[test_log::test]
+fn rent_exempt_account_is_untouched_across_epochs() {
+    let from_keypair = Keypair::new();
+    let new_account = Keypair::new();
+    let from = from_keypair.pubkey();
+
+    let mut svm = LiteSVM::new();
+    let space = 10;
+    let rent_amount = svm.minimum_balance_for_rent_exemption(space);
+    svm.airdrop(&from, rent_amount + 10 * LAMPORTS_PER_SOL).unwrap();
+
+    let instruction = create_account(
+        &from,
+        &new_account.pubkey(),
+        rent_amount,
+        space as u64,
+        &solana_sdk_ids::system_program::id(),
+    );
+    let tx = Transaction::new(
+        &[&from_keypair, &new_account],
+        Message::new(&[instruction], Some(&from)),
+        svm.latest_blockhash(),
+    );
+    svm.send_transaction(tx).unwrap();
+
+    svm.warp_to_epoch(10);
+
+    // Touch the account by transferring through it so rent collection runs.
+    let transfer_ix = transfer(&from, &new_account.pubkey(), 0);
+    let tx = Transaction::new(
+        &[&from_keypair],
+        Message::new(&[transfer_ix], Some(&from)),
+        svm.latest_blockhash(),
+    );
+    svm.send_transaction(tx).unwrap();
+
+    let lamports_after = svm.get_account(&new_account.pubkey()).unwrap().lamports;
+    assert_eq!(lamports_after, rent_amount);
+}
+
+#[test_log::test]
+fn non_exempt_account_is_charged_rent_over_epochs() {
+    let from_keypair = Keypair::new();
+    let new_account_keypair = Keypair::new();
+    let from = from_keypair.pubkey();
+    let new_account = new_account_keypair.pubkey();
+
+    let mut svm = LiteSVM::new();
+    svm.airdrop(&from, 10 * LAMPORTS_PER_SOL).unwrap();
+
+    // Fund the account with less than the rent-exempt minimum.
+    let space = 100u64;
+    let rent_exempt_minimum = svm.minimum_balance_for_rent_exemption(space as usize);
+    let partial_funding = rent_exempt_minimum / 2;
+
+    let create_ix = create_account(
+        &from,
+        &new_account,
+        partial_funding,
+        space,
+        &solana_sdk_ids::system_program::id(),
+    );
+    let tx = Transaction::new(
+        &[&from_keypair, &new_account_keypair],
+        Message::new(&[create_ix], Some(&from)),
+        svm.latest_blockhash(),
+    );
+    svm.send_transaction(tx).unwrap();
+
+    svm.warp_to_epoch(10);
+
+    // Loading the account as writable (a no-op re-assign to its current
+    // owner) re-evaluates rent for it.
+    let assign_ix = assign(&new_account, &solana_sdk_ids::system_program::id());
+    let tx = Transaction::new(
+        &[&from_keypair, &new_account_keypair],
+        Message::new(&[assign_ix], Some(&from)),
+        svm.latest_blockhash(),
+    );
+    svm.send_transaction(tx).unwrap();
+
+    // Only 10 of a year's worth of epochs have elapsed, so the account
+    // should be charged a fraction of its deposit, not the whole thing.
+    let balance_after = svm.get_account(&new_account).unwrap().lamports;
+    assert!(balance_after < partial_funding);
+    assert!(balance_after > 0);
+}
+
+#[test_log::test]
+fn non_exempt_account_is_purged_once_rent_exhausts_balance() {
+    let from_keypair = Keypair::new();
+    let new_account_keypair = Keypair::new();
+    let from = from_keypair.pubkey();
+    let new_account = new_account_keypair.pubkey();
+
+    let mut svm = LiteSVM::new();
+    svm.airdrop(&from, 10 * LAMPORTS_PER_SOL).unwrap();
+
+    // Fund the account with less than the rent-exempt minimum.
+    let space = 100u64;
+    let rent_exempt_minimum = svm.minimum_balance_for_rent_exemption(space as usize);
+    let partial_funding = rent_exempt_minimum / 2;
+
+    let create_ix = create_account(
+        &from,
+        &new_account,
+        partial_funding,
+        space,
+        &solana_sdk_ids::system_program::id(),
+    );
+    let tx = Transaction::new(
+        &[&from_keypair, &new_account_keypair],
+        Message::new(&[create_ix], Some(&from)),
+        svm.latest_blockhash(),
+    );
+    svm.send_transaction(tx).unwrap();
+
+    // Warp far enough that the accumulated rent due exceeds what's left in
+    // the account.
+    svm.warp_to_epoch(500);
+
+    let assign_ix = assign(&new_account, &solana_sdk_ids::system_program::id());
+    let tx = Transaction::new(
+        &[&from_keypair, &new_account_keypair],
+        Message::new(&[assign_ix], Some(&from)),
+        svm.latest_blockhash(),
+    );
+    svm.send_transaction(tx).unwrap();
+
+    // The account should have been purged once its balance dropped below the
+    // rent-exempt threshold with nothing left to pay.
+    assert!(svm.get_account(&new_account).is_none());
+}
+
+#[test_log::test]
+fn system_allocate_account_is_reclaimed_for_unpaid_rent() {
+    let from_keypair = Keypair::new();
+    let new_account_keypair = Keypair::new();
+    let from = from_keypair.pubkey();
+    let new_account = new_account_keypair.pubkey();
+
+    let mut svm = LiteSVM::new();
+    svm.airdrop(&from, 10 * LAMPORTS_PER_SOL).unwrap();
+
+    let instruction = allocate(&new_account, 10);
+    let tx = Transaction::new(
+        &[&from_keypair, &new_account_keypair],
+        Message::new(&[instruction], Some(&from)),
+        svm.latest_blockhash(),
+    );
+    svm.send_transaction(tx).unwrap();
+
+    // Allocated but never funded: it holds zero lamports, so it is purged as
+    // soon as the transaction commits.
+    assert!(svm.get_account(&new_account).is_none());
+}
+
+#[test_log::test]
+fn executable_accounts_skip_rent_collection() {
+    let from_keypair = Keypair::new();
+    let from = from_keypair.pubkey();
+    // A stand-in "program" account: executable and nowhere near the
+    // rent-exempt minimum for an empty account, so if the `executable` skip
+    // didn't work it would be drained to zero and purged.
+    let program_keypair = Keypair::new();
+    let program = program_keypair.pubkey();
+
+    let mut svm = LiteSVM::new();
+    svm.airdrop(&from, 10 * LAMPORTS_PER_SOL).unwrap();
+    svm.set_account(
+        program,
+        Account {
+            lamports: 1,
+            owner: solana_sdk_ids::native_loader::id(),
+            executable: true,
+            ..Account::default()
+        },
+    )
+    .unwrap();
+
+    svm.warp_to_epoch(100);
+
+    // Transfer into it so it's loaded as writable and rent collection runs.
+    let transfer_ix = transfer(&from, &program, 1);
+    let tx = Transaction::new(
+        &[&from_keypair],
+        Message::new(&[transfer_ix], Some(&from)),
+        svm.latest_blockhash(),
+    );
+    svm.send_transaction(tx).unwrap();
+
+    let program_account = svm.get_account(&program).unwrap();
+    assert_eq!(program_account.lamports, 2);
+    assert_eq!(program_account.rent_epoch, 100);
+}
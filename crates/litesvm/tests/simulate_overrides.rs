@@ -0,0 +1,116 @@
+use {
+    litesvm::LiteSVM,
+    solana_account::Account,
+    solana_keypair::Keypair,
+    solana_message::Message,
+    solana_native_token::LAMPORTS_PER_SOL,
+    solana_pubkey::Pubkey,
+    solana_signer::Signer,
+    solana_system_interface::instruction::transfer,
+    solana_transaction::Transaction,
+    std::collections::HashMap,
+};
+
+#[test_log::test]
+fn override_account_is_visible_during_simulation() {
+    let svm = LiteSVM::new();
+    let from_keypair = Keypair::new();
+    let from = from_keypair.pubkey();
+    let to = Pubkey::new_unique();
+
+    // `from` has no funds in the real store; only the override has any.
+    let mut overrides = HashMap::new();
+    overrides.insert(
+        from,
+        Account {
+            lamports: 10 * LAMPORTS_PER_SOL,
+            data: vec![],
+            owner: solana_sdk_ids::system_program::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    let ix = transfer(&from, &to, 1_000_000);
+    let tx = Transaction::new(
+        &[&from_keypair],
+        Message::new(&[ix], Some(&from)),
+        svm.latest_blockhash(),
+    );
+
+    let result = svm
+        .simulate_transaction_with_overrides(tx, overrides)
+        .unwrap();
+
+    let to_account = result
+        .post_accounts
+        .iter()
+        .find(|(pubkey, _)| *pubkey == to)
+        .map(|(_, account)| account)
+        .unwrap();
+    assert_eq!(to_account.lamports, 1_000_000);
+
+    // The committed state is untouched.
+    assert!(svm.get_account(&from).is_none());
+    assert!(svm.get_account(&to).is_none());
+}
+
+#[test_log::test]
+fn override_does_not_affect_other_accounts() {
+    let mut svm = LiteSVM::new();
+    let from_keypair = Keypair::new();
+    let from = from_keypair.pubkey();
+    let to = Pubkey::new_unique();
+    svm.airdrop(&from, 1_000_000).unwrap();
+
+    let real_account_before = svm.get_account(&from).unwrap();
+
+    let overrides = HashMap::from([(
+        to,
+        Account {
+            lamports: 5_000_000,
+            data: vec![],
+            owner: solana_sdk_ids::system_program::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    )]);
+
+    let ix = transfer(&from, &to, 100);
+    let tx = Transaction::new(
+        &[&from_keypair],
+        Message::new(&[ix], Some(&from)),
+        svm.latest_blockhash(),
+    );
+    svm.simulate_transaction_with_overrides(tx, overrides)
+        .unwrap();
+
+    // Simulation must not commit anything back to the store.
+    assert_eq!(svm.get_account(&from).unwrap(), real_account_before);
+    assert!(svm.get_account(&to).is_none());
+}
+
+#[test_log::test]
+fn simulation_rejects_invalid_signatures() {
+    let svm = LiteSVM::new();
+    let from_keypair = Keypair::new();
+    let from = from_keypair.pubkey();
+    let to = Pubkey::new_unique();
+
+    let ix = transfer(&from, &to, 1_000_000);
+    let mut tx = Transaction::new(
+        &[&from_keypair],
+        Message::new(&[ix], Some(&from)),
+        svm.latest_blockhash(),
+    );
+    // Corrupt the signature so it no longer matches the message, same as a
+    // real `send_transaction` call would reject.
+    tx.signatures[0] = solana_signature::Signature::default();
+
+    let result = svm.simulate_transaction_with_overrides(tx, HashMap::new());
+
+    assert_eq!(
+        result.unwrap_err().err,
+        solana_transaction_error::TransactionError::SignatureFailure
+    );
+}
@@ -0,0 +1,132 @@
+use {
+    litesvm::LiteSVM,
+    solana_keypair::Keypair,
+    solana_message::Message,
+    solana_nonce::state::State as NonceState,
+    solana_pubkey::Pubkey,
+    solana_signer::Signer,
+    solana_system_interface::instruction::{
+        advance_nonce_account, create_nonce_account, transfer,
+    },
+    solana_transaction::Transaction,
+};
+
+#[test_log::test]
+fn duplicate_signature_is_rejected() {
+    let mut svm = LiteSVM::new();
+    let from_keypair = Keypair::new();
+    let from = from_keypair.pubkey();
+    let to = Pubkey::new_unique();
+    svm.airdrop(&from, 1_000_000).unwrap();
+
+    let ix = transfer(&from, &to, 100);
+    let tx = Transaction::new(
+        &[&from_keypair],
+        Message::new(&[ix], Some(&from)),
+        svm.latest_blockhash(),
+    );
+    let signature = tx.signatures[0];
+
+    let first_meta = svm.send_transaction(tx.clone()).unwrap();
+    let result = svm.send_transaction(tx);
+
+    assert!(result.is_err());
+    // The cached status should carry the fee actually charged when the
+    // transaction was first processed, not a hardcoded stand-in.
+    let cached = svm.get_signature_status(&signature).unwrap().unwrap();
+    assert_eq!(cached, first_meta);
+}
+
+#[test_log::test]
+fn signature_status_is_evicted_once_blockhash_ages_out() {
+    let mut svm = LiteSVM::new();
+    let from_keypair = Keypair::new();
+    let from = from_keypair.pubkey();
+    let to = Pubkey::new_unique();
+    svm.airdrop(&from, 10_000_000).unwrap();
+
+    let ix = transfer(&from, &to, 100);
+    let tx = Transaction::new(
+        &[&from_keypair],
+        Message::new(&[ix], Some(&from)),
+        svm.latest_blockhash(),
+    );
+    let signature = tx.signatures[0];
+
+    svm.send_transaction(tx.clone()).unwrap();
+
+    // Age the blockhash (and the status cache entry keyed on it) out of the
+    // queue.
+    for _ in 0..400 {
+        svm.expire_blockhash();
+    }
+
+    assert!(svm.get_signature_status(&signature).is_none());
+}
+
+#[test_log::test]
+fn nonce_transaction_replay_protection_survives_blockhash_queue_eviction() {
+    let mut svm = LiteSVM::new();
+    let payer_keypair = Keypair::new();
+    let payer = payer_keypair.pubkey();
+    let nonce_keypair = Keypair::new();
+    let nonce_pubkey = nonce_keypair.pubkey();
+    svm.airdrop(&payer, 10_000_000).unwrap();
+
+    let rent = svm.minimum_balance_for_rent_exemption(NonceState::size());
+    let ixs = create_nonce_account(&payer, &nonce_pubkey, &payer, rent);
+    let tx = Transaction::new(
+        &[&payer_keypair, &nonce_keypair],
+        Message::new(&ixs, Some(&payer)),
+        svm.latest_blockhash(),
+    );
+    svm.send_transaction(tx).unwrap();
+
+    let account = svm.get_account(&nonce_pubkey).unwrap();
+    let durable_blockhash =
+        match bincode::deserialize::<solana_nonce::versions::Versions>(&account.data)
+            .unwrap()
+            .state()
+        {
+            NonceState::Initialized(data) => data.blockhash(),
+            NonceState::Uninitialized => panic!("nonce account not initialized"),
+        };
+
+    let ix = advance_nonce_account(&nonce_pubkey, &payer);
+    let tx = Transaction::new(
+        &[&payer_keypair],
+        Message::new(&[ix], Some(&payer)),
+        durable_blockhash,
+    );
+    let signature = tx.signatures[0];
+    svm.send_transaction(tx.clone()).unwrap();
+
+    // A nonce transaction's key is never pushed onto the blockhash queue, so
+    // it must not be evicted from the status cache on the very next
+    // `expire_blockhash` call -- only once it ages past the same window a
+    // regular blockhash entry would.
+    svm.expire_blockhash();
+    assert!(svm.get_signature_status(&signature).is_some());
+    assert!(svm.send_transaction(tx).is_err());
+}
+
+#[test_log::test]
+fn status_cache_can_be_disabled() {
+    let mut svm = LiteSVM::new().with_status_cache(false);
+    let from_keypair = Keypair::new();
+    let from = from_keypair.pubkey();
+    let to = Pubkey::new_unique();
+    svm.airdrop(&from, 1_000_000).unwrap();
+
+    let ix = transfer(&from, &to, 100);
+    let tx = Transaction::new(
+        &[&from_keypair],
+        Message::new(&[ix], Some(&from)),
+        svm.latest_blockhash(),
+    );
+
+    svm.send_transaction(tx.clone()).unwrap();
+    let result = svm.send_transaction(tx);
+
+    assert!(result.is_ok());
+}
@@ -1,5 +1,6 @@
 use {
     litesvm::LiteSVM,
+    solana_account::Account,
     solana_keypair::Keypair,
     solana_message::Message,
     solana_native_token::LAMPORTS_PER_SOL,
@@ -35,6 +36,66 @@ fn system_transfer() {
     assert_eq!(to_account.unwrap().lamports, 64);
 }
 
+#[test_log::test]
+fn transfer_from_non_system_owned_account_fails() {
+    let not_owner = Pubkey::new_unique();
+    let from_keypair = Keypair::new();
+    let from = from_keypair.pubkey();
+    let to = Pubkey::new_unique();
+
+    let mut svm = LiteSVM::new();
+    svm.set_account(
+        from,
+        Account {
+            lamports: 10 * LAMPORTS_PER_SOL,
+            owner: not_owner,
+            ..Account::default()
+        },
+    )
+    .unwrap();
+
+    // A plain `transfer` out of an account a real validator would never let
+    // the System Program touch, since it isn't the owner.
+    let instruction = transfer(&from, &to, 64);
+    let tx = Transaction::new(
+        &[&from_keypair],
+        Message::new(&[instruction], Some(&from)),
+        svm.latest_blockhash(),
+    );
+    assert!(svm.send_transaction(tx).is_err());
+    assert_eq!(svm.get_account(&to), None);
+}
+
+#[test_log::test]
+fn transfer_from_system_owned_account_with_data_fails() {
+    let from_keypair = Keypair::new();
+    let from = from_keypair.pubkey();
+    let to = Pubkey::new_unique();
+
+    let mut svm = LiteSVM::new();
+    svm.set_account(
+        from,
+        Account {
+            lamports: 10 * LAMPORTS_PER_SOL,
+            data: vec![0; 1],
+            owner: solana_sdk_ids::system_program::id(),
+            ..Account::default()
+        },
+    )
+    .unwrap();
+
+    // System-owned but non-empty, e.g. a nonce account: `transfer` still
+    // isn't the right instruction to move lamports out of it.
+    let instruction = transfer(&from, &to, 64);
+    let tx = Transaction::new(
+        &[&from_keypair],
+        Message::new(&[instruction], Some(&from)),
+        svm.latest_blockhash(),
+    );
+    assert!(svm.send_transaction(tx).is_err());
+    assert_eq!(svm.get_account(&to), None);
+}
+
 #[test_log::test]
 fn system_create_account() {
     let from_keypair = Keypair::new();
@@ -91,6 +152,33 @@ fn system_allocate_account() {
     assert!(svm.get_account(&new_account).is_none());
 }
 
+#[test_log::test]
+fn system_allocate_account_funded_before_allocation() {
+    let from_keypair = Keypair::new();
+    let new_account_keypair = Keypair::new();
+    let from = from_keypair.pubkey();
+    let new_account = new_account_keypair.pubkey();
+
+    let mut svm = LiteSVM::new();
+    svm.airdrop(&from, 10 * LAMPORTS_PER_SOL).unwrap();
+
+    // Funding an address before allocating/assigning it is a standard
+    // two-step account-setup pattern; lamports alone shouldn't make
+    // `Allocate` think the account is already initialized.
+    svm.airdrop(&new_account, LAMPORTS_PER_SOL).unwrap();
+
+    let instruction = allocate(&new_account, 10);
+    let tx = Transaction::new(
+        &[&from_keypair, &new_account_keypair],
+        Message::new(&[instruction], Some(&from)),
+        svm.latest_blockhash(),
+    );
+    svm.send_transaction(tx).unwrap();
+
+    let account = svm.get_account(&new_account).unwrap();
+    assert_eq!(account.data.len(), 10);
+}
+
 #[test_log::test]
 fn test_get_program_accounts() {
     let mut svm = LiteSVM::new();